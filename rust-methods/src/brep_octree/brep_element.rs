@@ -1,18 +1,111 @@
-use std::ops::{Add, Mul};
+use std::marker::PhantomData;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
+/// Marker unit for a vector with no enforced physical meaning. This is the
+/// default so plain `Vector3D<T>` usage is unaffected by unit-tagging.
 #[derive(Clone, Copy, PartialEq, Debug)]
-struct Vector3D {
-    i: f64,
-    j: f64,
-    k: f64,
+pub(crate) struct Untagged;
+
+/// Marker unit for an absolute position in space (millimetres).
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Position;
+
+/// Marker unit for a relative offset between two positions (millimetres).
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Displacement;
+
+/// Marker unit for a rate of change of position (millimetres per second).
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Velocity;
+
+/// A duration in seconds, the only scalar a `Velocity` vector may be
+/// multiplied by with `*`, turning it into a `Displacement`. (In-place `*=`
+/// on a `Velocity` takes a plain scalar and keeps the `Velocity` unit.)
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Seconds(f64);
+
+/// Declares which unit may be added to `Self`, and what unit the sum carries.
+/// This is what lets the type system reject e.g. adding a `Velocity` to a
+/// `Position` while still allowing `Position + Displacement -> Position`.
+pub(crate) trait UnitAdd<Rhs> {
+    type Output;
+}
+
+impl UnitAdd<Untagged> for Untagged {
+    type Output = Untagged;
+}
+
+impl UnitAdd<Displacement> for Position {
+    type Output = Position;
+}
+
+impl UnitAdd<Displacement> for Displacement {
+    type Output = Displacement;
+}
+
+/// Declares which unit may be subtracted from `Self`, and what unit the
+/// difference carries: subtracting two `Position`s yields the `Displacement`
+/// between them, while subtracting a `Displacement` from a `Position` yields
+/// another `Position`.
+pub(crate) trait UnitSub<Rhs> {
+    type Output;
+}
+
+impl UnitSub<Untagged> for Untagged {
+    type Output = Untagged;
+}
+
+impl UnitSub<Position> for Position {
+    type Output = Displacement;
+}
+
+impl UnitSub<Displacement> for Position {
+    type Output = Position;
+}
+
+impl UnitSub<Displacement> for Displacement {
+    type Output = Displacement;
+}
+
+/// Marker trait for units that behave like free vectors under uniform
+/// scalar scaling, negation, and division. `Position` deliberately does not
+/// implement this: it is point-like, and the only way to move one is by
+/// `Add`/`Sub` with a `Displacement`, not by scaling it directly.
+trait Scalable {}
+
+impl Scalable for Untagged {}
+impl Scalable for Displacement {}
+impl Scalable for Velocity {}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) struct Vector3D<T = f64, U = Untagged> {
+    i: T,
+    j: T,
+    k: T,
+    _unit: PhantomData<U>,
+}
+
+impl<T: Copy, U> Vector3D<T, U> {
+    /// Splats a single scalar across all three components.
+    pub fn from_value(v: T) -> Self {
+        Vector3D {
+            i: v,
+            j: v,
+            k: v,
+            _unit: PhantomData,
+        }
+    }
 }
 
-impl Vector3D {
+impl Vector3D<f64> {
     pub fn new(i: impl Into<f64>, j: impl Into<f64>, k: impl Into<f64>) -> Self {
         Vector3D {
             i: i.into(),
             j: j.into(),
             k: k.into(),
+            _unit: PhantomData,
         }
     }
 
@@ -27,35 +120,308 @@ impl Vector3D {
     pub fn dot(&self, other: &Vector3D) -> f64 {
         self.i * other.i + self.j * other.j + self.k * other.k
     }
+
+    pub fn cross(&self, other: &Vector3D) -> Vector3D {
+        Vector3D {
+            i: self.j * other.k - self.k * other.j,
+            j: self.k * other.i - self.i * other.k,
+            k: self.i * other.j - self.j * other.i,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Returns the unit-length vector, or `None` if `self` is too short to
+    /// normalize reliably (length below `1e-12`), which happens on
+    /// degenerate edges.
+    pub fn normalize(&self) -> Option<Vector3D> {
+        let length = self.length();
+        if length < 1e-12 {
+            None
+        } else {
+            Some(self.normalize_unchecked())
+        }
+    }
+
+    /// Normalizes without checking for a near-zero length; divides by zero
+    /// (producing `NaN`/`inf` components) on degenerate vectors.
+    pub fn normalize_unchecked(&self) -> Vector3D {
+        let length = self.length();
+        Vector3D {
+            i: self.i / length,
+            j: self.j / length,
+            k: self.k / length,
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<U> Vector3D<f64, U> {
+    /// Default tolerance used by [`Vector3D::approx_eq`].
+    const DEFAULT_EPSILON: f64 = 1e-9;
+
+    /// Component-wise equality within [`Vector3D::DEFAULT_EPSILON`], for
+    /// comparing results that have gone through a cross product or
+    /// normalization and are no longer bitwise-exact.
+    pub fn approx_eq(&self, other: &Vector3D<f64, U>) -> bool {
+        self.approx_eq_eps(other, Self::DEFAULT_EPSILON)
+    }
+
+    /// Component-wise equality within a caller-supplied tolerance, combining
+    /// an absolute and a relative term like cgmath/euclid: a component pair
+    /// passes if it is within `eps` outright, or within `eps` scaled by the
+    /// larger of the two magnitudes. The absolute term keeps comparisons
+    /// near zero meaningful; the relative term keeps `eps` meaningful on the
+    /// hundreds-of-millimetres coordinates a WAAM toolpath actually uses.
+    pub fn approx_eq_eps(&self, other: &Vector3D<f64, U>, eps: f64) -> bool {
+        Self::component_approx_eq(self.i, other.i, eps)
+            && Self::component_approx_eq(self.j, other.j, eps)
+            && Self::component_approx_eq(self.k, other.k, eps)
+    }
+
+    fn component_approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        let diff = (a - b).abs();
+        // `diff.is_finite()` rules out the case where `a`/`b` (or their
+        // difference) are `inf`/`NaN`, e.g. from an overflowed coordinate:
+        // without it, `inf <= inf` would make two different infinities
+        // compare as approximately equal.
+        diff.is_finite() && (diff <= eps || diff <= eps * a.abs().max(b.abs()))
+    }
 }
 
-impl Add for Vector3D {
-    type Output = Vector3D;
+/// Asserts that two `Vector3D<f64, _>` values are equal within a tolerance,
+/// optionally a caller-supplied one, printing both sides on failure like
+/// `assert_eq!`.
+#[cfg(test)]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => assert!(
+                left.approx_eq(right),
+                "assertion failed: `left.approx_eq(right)`\n  left: `{:?}`\n right: `{:?}`",
+                left,
+                right
+            ),
+        }
+    };
+    ($left:expr, $right:expr, $eps:expr $(,)?) => {
+        match (&$left, &$right, &$eps) {
+            (left, right, eps) => assert!(
+                left.approx_eq_eps(right, *eps),
+                "assertion failed: `left.approx_eq_eps(right, {:?})`\n  left: `{:?}`\n right: `{:?}`",
+                eps,
+                left,
+                right
+            ),
+        }
+    };
+}
+
+impl<T, U1, U2> Add<Vector3D<T, U2>> for Vector3D<T, U1>
+where
+    T: Add<Output = T>,
+    U1: UnitAdd<U2>,
+{
+    type Output = Vector3D<T, U1::Output>;
 
-    fn add(self, rhs: Self) -> Self::Output {
-        Self {
+    fn add(self, rhs: Vector3D<T, U2>) -> Self::Output {
+        Vector3D {
             i: self.i + rhs.i,
             j: self.j + rhs.j,
             k: self.k + rhs.k,
+            _unit: PhantomData,
         }
     }
 }
 
-impl<T> Mul<T> for Vector3D
+impl<T, S> Mul<S> for Vector3D<T, Untagged>
 where
-    T: Into<f64>,
+    T: Mul<Output = T> + Copy,
+    S: Into<T>,
 {
-    type Output = Vector3D;
+    type Output = Vector3D<T, Untagged>;
 
-    fn mul(self, scalar: T) -> Vector3D {
-        let scalar_f64: f64 = scalar.into();
+    fn mul(self, scalar: S) -> Vector3D<T, Untagged> {
+        let scalar: T = scalar.into();
         Vector3D {
-            i: self.i * scalar_f64,
-            j: self.j * scalar_f64,
-            k: self.k * scalar_f64,
+            i: self.i * scalar,
+            j: self.j * scalar,
+            k: self.k * scalar,
+            _unit: PhantomData,
         }
     }
 }
+
+impl<T, S> Mul<S> for Vector3D<T, Displacement>
+where
+    T: Mul<Output = T> + Copy,
+    S: Into<T>,
+{
+    type Output = Vector3D<T, Displacement>;
+
+    fn mul(self, scalar: S) -> Vector3D<T, Displacement> {
+        let scalar: T = scalar.into();
+        Vector3D {
+            i: self.i * scalar,
+            j: self.j * scalar,
+            k: self.k * scalar,
+            _unit: PhantomData,
+        }
+    }
+}
+
+/// Scaling a `Velocity` by a duration yields a `Displacement`, e.g.
+/// `velocity * Seconds(dt)` to advance a toolpath by one timestep.
+impl<T> Mul<Seconds> for Vector3D<T, Velocity>
+where
+    T: Mul<Output = T> + Copy + From<f64>,
+{
+    type Output = Vector3D<T, Displacement>;
+
+    fn mul(self, rhs: Seconds) -> Vector3D<T, Displacement> {
+        let dt: T = rhs.0.into();
+        Vector3D {
+            i: self.i * dt,
+            j: self.j * dt,
+            k: self.k * dt,
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<T, U1, U2> Sub<Vector3D<T, U2>> for Vector3D<T, U1>
+where
+    T: Sub<Output = T>,
+    U1: UnitSub<U2>,
+{
+    type Output = Vector3D<T, U1::Output>;
+
+    fn sub(self, rhs: Vector3D<T, U2>) -> Self::Output {
+        Vector3D {
+            i: self.i - rhs.i,
+            j: self.j - rhs.j,
+            k: self.k - rhs.k,
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<T, U> Neg for Vector3D<T, U>
+where
+    T: Neg<Output = T>,
+    U: Scalable,
+{
+    type Output = Vector3D<T, U>;
+
+    fn neg(self) -> Self::Output {
+        Vector3D {
+            i: -self.i,
+            j: -self.j,
+            k: -self.k,
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<T, U, S> Div<S> for Vector3D<T, U>
+where
+    T: Div<Output = T> + Copy,
+    S: Into<T>,
+    U: Scalable,
+{
+    type Output = Vector3D<T, U>;
+
+    fn div(self, scalar: S) -> Self::Output {
+        let scalar: T = scalar.into();
+        Vector3D {
+            i: self.i / scalar,
+            j: self.j / scalar,
+            k: self.k / scalar,
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<T, U1, U2> AddAssign<Vector3D<T, U2>> for Vector3D<T, U1>
+where
+    T: AddAssign + Copy,
+    U1: UnitAdd<U2, Output = U1>,
+{
+    fn add_assign(&mut self, rhs: Vector3D<T, U2>) {
+        self.i += rhs.i;
+        self.j += rhs.j;
+        self.k += rhs.k;
+    }
+}
+
+impl<T, U1, U2> SubAssign<Vector3D<T, U2>> for Vector3D<T, U1>
+where
+    T: SubAssign + Copy,
+    U1: UnitSub<U2, Output = U1>,
+{
+    fn sub_assign(&mut self, rhs: Vector3D<T, U2>) {
+        self.i -= rhs.i;
+        self.j -= rhs.j;
+        self.k -= rhs.k;
+    }
+}
+
+impl<T, U, S> MulAssign<S> for Vector3D<T, U>
+where
+    T: MulAssign + Copy,
+    S: Into<T>,
+    U: Scalable,
+{
+    fn mul_assign(&mut self, scalar: S) {
+        let scalar: T = scalar.into();
+        self.i *= scalar;
+        self.j *= scalar;
+        self.k *= scalar;
+    }
+}
+
+impl<T, U, S> DivAssign<S> for Vector3D<T, U>
+where
+    T: DivAssign + Copy,
+    S: Into<T>,
+    U: Scalable,
+{
+    fn div_assign(&mut self, scalar: S) {
+        let scalar: T = scalar.into();
+        self.i /= scalar;
+        self.j /= scalar;
+        self.k /= scalar;
+    }
+}
+
+/// Pairwise dot products over two equal-length vertex buffers.
+///
+/// This is the batch-layer entry point for hot paths (e.g. scoring every
+/// edge of an octree cell against a probe direction) that would otherwise
+/// call [`Vector3D::dot`] in a loop. There is no `Cargo.toml` in this tree
+/// to declare a `simd` feature or a stable SIMD crate dependency, so this
+/// is a straightforward scalar loop rather than a `std::simd` (nightly-only)
+/// implementation; revisit with `wide` or `glam` once the crate has a real
+/// manifest to pin that dependency in.
+pub(crate) fn dot_many(a: &[Vector3D], b: &[Vector3D]) -> Vec<f64> {
+    assert_eq!(a.len(), b.len(), "dot_many requires equal-length slices");
+    a.iter().zip(b).map(|(x, y)| x.dot(y)).collect()
+}
+
+/// Component-wise sum over two equal-length vertex buffers.
+pub(crate) fn add_many(a: &[Vector3D], b: &[Vector3D]) -> Vec<Vector3D> {
+    assert_eq!(a.len(), b.len(), "add_many requires equal-length slices");
+    a.iter().zip(b).map(|(&x, &y)| x + y).collect()
+}
+
+/// Scales every vector in a vertex buffer by the same scalar.
+pub(crate) fn scale_many(values: &[Vector3D], scalar: f64) -> Vec<Vector3D> {
+    values.iter().map(|&v| v * scalar).collect()
+}
+
+/// Euclidean length of every vector in a vertex buffer.
+pub(crate) fn length_many(values: &[Vector3D]) -> Vec<f64> {
+    values.iter().map(Vector3D::length).collect()
+}
 #[cfg(test)]
 mod brep_element_tests {
     use super::*;
@@ -67,7 +433,8 @@ mod brep_element_tests {
             Vector3D {
                 i: 1.0,
                 j: 2.0,
-                k: 3.0
+                k: 3.0,
+                _unit: PhantomData
             }
         )
     }
@@ -104,4 +471,300 @@ mod brep_element_tests {
 
         assert_eq!(v1.dot(&v2), 2.0);
     }
+
+    #[test]
+    fn test_cross_product() {
+        let v1 = Vector3D::new(1, 0, 0);
+        let v2 = Vector3D::new(0, 1, 0);
+
+        assert_eq!(v1.cross(&v2), Vector3D::new(0, 0, 1));
+    }
+
+    #[test]
+    fn test_normalize() {
+        let v = Vector3D::new(3.0, 0.0, 4.0);
+
+        assert_eq!(v.normalize(), Some(Vector3D::new(0.6, 0.0, 0.8)));
+    }
+
+    #[test]
+    fn test_normalize_degenerate_vector_is_none() {
+        let v = Vector3D::new(0.0, 0.0, 0.0);
+
+        assert_eq!(v.normalize(), None);
+    }
+
+    #[test]
+    fn test_normalize_unchecked() {
+        let v = Vector3D::new(0.0, 2.0, 0.0);
+
+        assert_eq!(v.normalize_unchecked(), Vector3D::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn test_from_value_splats_scalar() {
+        assert_eq!(Vector3D::from_value(2.0), Vector3D::new(2.0, 2.0, 2.0));
+    }
+
+    #[test]
+    fn test_from_value_with_integer_lattice_coordinates() {
+        let v: Vector3D<i32> = Vector3D::from_value(4);
+
+        assert_eq!(
+            v,
+            Vector3D {
+                i: 4,
+                j: 4,
+                k: 4,
+                _unit: PhantomData
+            }
+        );
+    }
+
+    #[test]
+    fn test_add_with_non_default_scalar_type() {
+        let v1: Vector3D<i32> = Vector3D {
+            i: 1,
+            j: 2,
+            k: 3,
+            _unit: PhantomData,
+        };
+        let v2: Vector3D<i32> = Vector3D {
+            i: 1,
+            j: 1,
+            k: 1,
+            _unit: PhantomData,
+        };
+
+        assert_eq!(
+            v1 + v2,
+            Vector3D {
+                i: 2,
+                j: 3,
+                k: 4,
+                _unit: PhantomData
+            }
+        );
+    }
+
+    #[test]
+    fn test_position_plus_displacement_is_position() {
+        let start: Vector3D<f64, Position> = Vector3D::from_value(1.0);
+        let offset: Vector3D<f64, Displacement> = Vector3D::from_value(2.0);
+
+        let end: Vector3D<f64, Position> = start + offset;
+
+        assert_eq!(end, Vector3D::from_value(3.0));
+    }
+
+    #[test]
+    fn test_displacement_plus_displacement_is_displacement() {
+        let a: Vector3D<f64, Displacement> = Vector3D::from_value(1.0);
+        let b: Vector3D<f64, Displacement> = Vector3D::from_value(2.0);
+
+        let sum: Vector3D<f64, Displacement> = a + b;
+
+        assert_eq!(sum, Vector3D::from_value(3.0));
+    }
+
+    #[test]
+    fn test_velocity_times_seconds_is_displacement() {
+        let v: Vector3D<f64, Velocity> = Vector3D::from_value(2.0);
+
+        let displacement: Vector3D<f64, Displacement> = v * Seconds(3.0);
+
+        assert_eq!(displacement, Vector3D::from_value(6.0));
+    }
+
+    #[test]
+    fn test_approx_eq_within_default_epsilon() {
+        let v1 = Vector3D::new(1.0, 2.0, 3.0);
+        let v2 = Vector3D::new(1.0 + 1e-10, 2.0, 3.0 - 1e-10);
+
+        assert!(v1.approx_eq(&v2));
+    }
+
+    #[test]
+    fn test_approx_eq_rejects_difference_beyond_default_epsilon() {
+        let v1 = Vector3D::new(1.0, 2.0, 3.0);
+        let v2 = Vector3D::new(1.1, 2.0, 3.0);
+
+        assert!(!v1.approx_eq(&v2));
+    }
+
+    #[test]
+    fn test_approx_eq_eps_with_custom_tolerance() {
+        let v1 = Vector3D::new(1.0, 2.0, 3.0);
+        let v2 = Vector3D::new(1.05, 2.0, 3.0);
+
+        assert!(v1.approx_eq_eps(&v2, 0.1));
+        assert!(!v1.approx_eq_eps(&v2, 0.01));
+    }
+
+    #[test]
+    fn test_approx_eq_default_epsilon_tolerates_rounding_at_waam_scale() {
+        // At WAAM coordinate magnitudes, rounding from a cross product or
+        // normalization can easily exceed an absolute 1e-9 tolerance. The
+        // difference here (5e-7) would fail an absolute-only check but
+        // passes once eps is also scaled by the coordinate magnitude
+        // (1e-9 * 3456.78 ~= 3.5e-6, comfortably above 5e-7).
+        let v1 = Vector3D::new(123.456, 789.012, 3456.78);
+        let v2 = Vector3D::new(123.456, 789.012, 3456.78 + 5e-7);
+
+        assert!(v1.approx_eq(&v2));
+    }
+
+    #[test]
+    fn test_approx_eq_rejects_overflowed_infinite_coordinates() {
+        let v1 = Vector3D::new(1e308, 1e308, 1e308);
+        let v2 = Vector3D::new(1e308 * 10.0, 1e308, 1e308);
+
+        assert!(!v1.approx_eq(&v2));
+    }
+
+    #[test]
+    fn test_normalize_is_approximately_unit_length() {
+        let v = Vector3D::new(1.0, 1.0, 1.0).normalize_unchecked();
+        let expected = 1.0 / 3.0_f64.sqrt();
+
+        assert_approx_eq!(v, Vector3D::new(expected, expected, expected));
+    }
+
+    #[test]
+    fn test_assert_approx_eq_macro_with_custom_epsilon() {
+        let v1 = Vector3D::new(1.0, 2.0, 3.0);
+        let v2 = Vector3D::new(1.05, 2.0, 3.0);
+
+        assert_approx_eq!(v1, v2, 0.1);
+    }
+
+    #[test]
+    fn test_sub_vector3d_from_vector3d() {
+        let v1 = Vector3D::new(1.0, 2.0, 3.0);
+        let v2 = Vector3D::new(2.0, 3.0, 5.0);
+
+        assert_eq!(v2 - v1, Vector3D::new(1.0, 1.0, 2.0));
+    }
+
+    #[test]
+    fn test_sub_position_from_position_is_displacement() {
+        let a: Vector3D<f64, Position> = Vector3D::from_value(5.0);
+        let b: Vector3D<f64, Position> = Vector3D::from_value(2.0);
+
+        let edge: Vector3D<f64, Displacement> = a - b;
+
+        assert_eq!(edge, Vector3D::from_value(3.0));
+    }
+
+    #[test]
+    fn test_neg_vector3d() {
+        let v = Vector3D::new(1.0, -2.0, 3.0);
+
+        assert_eq!(-v, Vector3D::new(-1.0, 2.0, -3.0));
+    }
+
+    #[test]
+    fn test_velocity_supports_scalar_scaling_negation_and_division() {
+        let v: Vector3D<f64, Velocity> = Vector3D::from_value(2.0);
+
+        assert_eq!(-v, Vector3D::from_value(-2.0));
+        assert_eq!(v / 2, Vector3D::from_value(1.0));
+
+        let mut scaled = v;
+        scaled *= 3;
+        assert_eq!(scaled, Vector3D::from_value(6.0));
+
+        let mut divided = v;
+        divided /= 2;
+        assert_eq!(divided, Vector3D::from_value(1.0));
+    }
+
+    // A `Vector3D<f64, Position>` deliberately does not implement `Neg`,
+    // `Div`, `MulAssign`, or `DivAssign` — a `Position` is point-like and
+    // may only move via `Add`/`Sub` with a `Displacement`. `position * 2.0`,
+    // `position *= 2.0`, `position / 2.0`, and `-position` are all expected
+    // compile errors, matching what the by-value `Mul` already enforces.
+
+    #[test]
+    fn test_div_vector3d_by_scalar() {
+        let v = Vector3D::new(2.0, 4.0, 6.0);
+
+        assert_eq!(v / 2, Vector3D::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_add_assign_accumulates_in_place() {
+        let mut total = Vector3D::new(0.0, 0.0, 0.0);
+        for v in [
+            Vector3D::new(1.0, 1.0, 1.0),
+            Vector3D::new(2.0, 3.0, 4.0),
+            Vector3D::new(1.0, 1.0, 1.0),
+        ] {
+            total += v;
+        }
+
+        assert_eq!(total, Vector3D::new(4.0, 5.0, 6.0));
+    }
+
+    #[test]
+    fn test_sub_assign_in_place() {
+        let mut v = Vector3D::new(5.0, 5.0, 5.0);
+        v -= Vector3D::new(1.0, 2.0, 3.0);
+
+        assert_eq!(v, Vector3D::new(4.0, 3.0, 2.0));
+    }
+
+    #[test]
+    fn test_mul_assign_in_place() {
+        let mut v = Vector3D::new(1.0, 2.0, 3.0);
+        v *= 3;
+
+        assert_eq!(v, Vector3D::new(3.0, 6.0, 9.0));
+    }
+
+    #[test]
+    fn test_div_assign_in_place() {
+        let mut v = Vector3D::new(2.0, 4.0, 6.0);
+        v /= 2;
+
+        assert_eq!(v, Vector3D::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_dot_many_matches_pairwise_dot() {
+        let a: Vec<Vector3D> = (0..6).map(|n| Vector3D::new(n, 1, 0)).collect();
+        let b: Vec<Vector3D> = (0..6).map(|n| Vector3D::new(1, n, 2)).collect();
+
+        let expected: Vec<f64> = a.iter().zip(&b).map(|(x, y)| x.dot(y)).collect();
+
+        assert_eq!(dot_many(&a, &b), expected);
+    }
+
+    #[test]
+    fn test_add_many_matches_pairwise_add() {
+        let a: Vec<Vector3D> = (0..5).map(|n| Vector3D::new(n, n, n)).collect();
+        let b: Vec<Vector3D> = (0..5).map(|_| Vector3D::new(1, 2, 3)).collect();
+
+        let expected: Vec<Vector3D> = a.iter().zip(&b).map(|(&x, &y)| x + y).collect();
+
+        assert_eq!(add_many(&a, &b), expected);
+    }
+
+    #[test]
+    fn test_scale_many_matches_scalar_mul() {
+        let values: Vec<Vector3D> = (0..7).map(|n| Vector3D::new(n, n, n)).collect();
+
+        let expected: Vec<Vector3D> = values.iter().map(|&v| v * 2.0).collect();
+
+        assert_eq!(scale_many(&values, 2.0), expected);
+    }
+
+    #[test]
+    fn test_length_many_matches_scalar_length() {
+        let values: Vec<Vector3D> = (0..9).map(|n| Vector3D::new(n, n, 0)).collect();
+
+        let expected: Vec<f64> = values.iter().map(Vector3D::length).collect();
+
+        assert_eq!(length_many(&values), expected);
+    }
 }